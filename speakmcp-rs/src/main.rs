@@ -1,7 +1,8 @@
 use rdev::{listen, Event, EventType};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use serde_json::json;
 use std::env;
+use std::io::{self, BufRead, Write};
 use std::process;
 
 #[derive(Serialize)]
@@ -36,70 +37,547 @@ impl std::fmt::Display for PlatformError {
 
 impl std::error::Error for PlatformError {}
 
+/// A modifier held while a combo's main key is tapped. Platform-neutral so
+/// both the Enigo and uinput backends can translate it to their own key
+/// types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ComboModifier {
+    Ctrl,
+    Shift,
+    Alt,
+    AltGr,
+    Meta,
+}
+
+/// A non-printable key reachable from a combo spec (`enter`, `f5`, ...).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NamedKey {
+    Enter,
+    Escape,
+    Tab,
+    Space,
+    Backspace,
+    Delete,
+    Up,
+    Down,
+    Left,
+    Right,
+    Home,
+    End,
+    PageUp,
+    PageDown,
+    F(u8),
+}
+
+/// The main key of a combo: either a literal character or a named key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ComboKey {
+    Char(char),
+    Named(NamedKey),
+}
+
+/// Parse a combo spec like `ctrl+shift+v`, `cmd+a`, `enter`, or `f5` into
+/// the modifiers to hold and the main key to tap.
+fn parse_key_combo(spec: &str) -> Result<(Vec<ComboModifier>, ComboKey), String> {
+    let mut parts: Vec<&str> = spec.split('+').map(str::trim).filter(|p| !p.is_empty()).collect();
+    if parts.is_empty() {
+        return Err(format!("empty key combo '{}'", spec));
+    }
+
+    let main = parts.pop().unwrap();
+    let mut modifiers = Vec::with_capacity(parts.len());
+    for part in parts {
+        modifiers.push(match part.to_lowercase().as_str() {
+            "ctrl" | "control" => ComboModifier::Ctrl,
+            "shift" => ComboModifier::Shift,
+            "alt" | "option" => ComboModifier::Alt,
+            "altgr" => ComboModifier::AltGr,
+            "cmd" | "meta" | "super" | "win" => ComboModifier::Meta,
+            other => return Err(format!("unknown modifier '{}' in combo '{}'", other, spec)),
+        });
+    }
+
+    Ok((modifiers, parse_combo_key(main)?))
+}
+
+fn parse_combo_key(spec: &str) -> Result<ComboKey, String> {
+    if spec.chars().count() == 1 {
+        return Ok(ComboKey::Char(spec.chars().next().unwrap()));
+    }
+
+    let lower = spec.to_lowercase();
+    if let Some(digits) = lower.strip_prefix('f') {
+        if let Ok(n) = digits.parse::<u8>() {
+            return Ok(ComboKey::Named(NamedKey::F(n)));
+        }
+    }
+
+    Ok(ComboKey::Named(match lower.as_str() {
+        "enter" | "return" => NamedKey::Enter,
+        "esc" | "escape" => NamedKey::Escape,
+        "tab" => NamedKey::Tab,
+        "space" => NamedKey::Space,
+        "backspace" => NamedKey::Backspace,
+        "delete" | "del" => NamedKey::Delete,
+        "up" => NamedKey::Up,
+        "down" => NamedKey::Down,
+        "left" => NamedKey::Left,
+        "right" => NamedKey::Right,
+        "home" => NamedKey::Home,
+        "end" => NamedKey::End,
+        "pageup" => NamedKey::PageUp,
+        "pagedown" => NamedKey::PageDown,
+        other => return Err(format!("unknown key '{}'", other)),
+    }))
+}
+
+/// uinput/virtual-keyboard text injection for Wayland sessions, where
+/// Enigo's XTest path is unavailable because compositors don't expose
+/// global input synthesis over the X protocol.
+#[cfg(target_os = "linux")]
+mod uinput_backend {
+    use super::PlatformError;
+    use std::thread::sleep;
+    use std::time::Duration;
+    use uinput::event::keyboard::Key;
+    use uinput::Device;
+
+    pub struct UinputKeyboard {
+        device: Device,
+    }
+
+    impl UinputKeyboard {
+        pub fn new() -> Result<Self, PlatformError> {
+            let device = uinput::default()
+                .and_then(|d| d.name("speakmcp-rs"))
+                .and_then(|d| d.event(uinput::event::Keyboard::All))
+                .and_then(|d| d.create())
+                .map_err(|e| {
+                    PlatformError::InitializationFailed(format!("uinput device creation failed: {}", e))
+                })?;
+
+            // UI_DEV_CREATE needs a short grace period before the kernel
+            // input subsystem registers the device, otherwise the first
+            // few events are silently dropped.
+            sleep(Duration::from_millis(150));
+
+            Ok(Self { device })
+        }
+
+        pub fn write_text(&mut self, text: &str) -> Result<(), PlatformError> {
+            for ch in text.chars() {
+                self.type_char(ch)?;
+            }
+            Ok(())
+        }
+
+        /// Synthesize Ctrl+V, for the clipboard-paste insertion mode.
+        pub fn paste(&mut self) -> Result<(), PlatformError> {
+            self.send_combo(&[super::ComboModifier::Ctrl], super::ComboKey::Char('v'))
+        }
+
+        /// Hold `modifiers`, tap `key`, then release the modifiers in
+        /// reverse order - the same general-purpose keystroke primitive
+        /// `key` exposes at the CLI layer.
+        pub fn send_combo(
+            &mut self,
+            modifiers: &[super::ComboModifier],
+            key: super::ComboKey,
+        ) -> Result<(), PlatformError> {
+            let (key, shifted) = combo_key_to_key(key).ok_or_else(|| {
+                PlatformError::InitializationFailed("unsupported key for combo".to_string())
+            })?;
+
+            let modifier_keys: Vec<Key> = modifiers.iter().copied().map(modifier_to_key).collect();
+            for m in &modifier_keys {
+                let _ = self.device.press(m);
+            }
+            if shifted {
+                let _ = self.device.press(&Key::LeftShift);
+            }
+
+            let result = self.device.click(&key);
+
+            if shifted {
+                let _ = self.device.release(&Key::LeftShift);
+            }
+            for m in modifier_keys.iter().rev() {
+                let _ = self.device.release(m);
+            }
+
+            result.map_err(|e| PlatformError::InitializationFailed(format!("uinput key emit failed: {}", e)))?;
+            self.device
+                .synchronize()
+                .map_err(|e| PlatformError::InitializationFailed(format!("uinput sync failed: {}", e)))
+        }
+
+        fn type_char(&mut self, ch: char) -> Result<(), PlatformError> {
+            let (key, shifted) = keysym_to_key(ch).ok_or_else(|| {
+                PlatformError::InitializationFailed(format!("no keycode mapping for '{}'", ch))
+            })?;
+
+            if shifted {
+                let _ = self.device.press(&Key::LeftShift);
+            }
+            let result = self.device.click(&key);
+            if shifted {
+                let _ = self.device.release(&Key::LeftShift);
+            }
+            result.map_err(|e| PlatformError::InitializationFailed(format!("uinput key emit failed: {}", e)))?;
+
+            // Each character is its own SYN_REPORT frame; syncing once for
+            // the whole string left the kernel free to coalesce a multi-key
+            // string into a single unreliable input frame.
+            self.device
+                .synchronize()
+                .map_err(|e| PlatformError::InitializationFailed(format!("uinput sync failed: {}", e)))
+        }
+    }
+
+    // The `uinput` crate issues UI_DEV_DESTROY when `Device` is dropped, so
+    // the synthetic keyboard never lingers in the input stack after exit.
+
+    /// Map a character to the uinput key that emits it, plus whether Shift
+    /// must be held. `xkbcommon` only turns the character into a keysym
+    /// (`utf32_to_keysym` rejecting characters with no keysym at all) -
+    /// letters, digits and whitespace go through a fixed US-QWERTY physical
+    /// keycode table (`ascii_letter_key`/`ascii_digit_key`), and punctuation
+    /// keysyms are looked up in `keysym_to_punctuation_key`'s own fixed
+    /// US-QWERTY table.
+    ///
+    /// KNOWN LIMITATION: uinput emits physical keycodes, and the compositor
+    /// re-applies the user's *active* layout to whatever keycode it
+    /// receives. On a non-US layout (AZERTY, Dvorak, QWERTZ, ...) the
+    /// keycode for e.g. 'a' under this table is whatever key sits in the
+    /// US "A" position, which may not produce 'a' under the user's layout.
+    /// A correct fix requires resolving each keysym against the process's
+    /// active xkb keymap (context + keymap + a keysym->keycode reverse
+    /// lookup) rather than a static US-QWERTY table.
+    fn keysym_to_key(ch: char) -> Option<(Key, bool)> {
+        use xkbcommon::xkb;
+
+        let keysym = xkb::utf32_to_keysym(ch as u32);
+        if keysym.raw() == xkb::keysyms::KEY_NoSymbol {
+            return None;
+        }
+
+        match ch {
+            'a'..='z' => Some((ascii_letter_key(ch.to_ascii_uppercase())?, false)),
+            'A'..='Z' => Some((ascii_letter_key(ch)?, true)),
+            '0'..='9' => Some((ascii_digit_key(ch)?, false)),
+            ' ' => Some((Key::Space, false)),
+            '\n' => Some((Key::Enter, false)),
+            '\t' => Some((Key::Tab, false)),
+            _ => keysym_to_punctuation_key(keysym),
+        }
+    }
+
+    /// Map the printable-ASCII punctuation keysyms to the US-QWERTY key that
+    /// produces them plus whether Shift is required, so transcriptions with
+    /// ordinary punctuation (commas, periods, apostrophes, ...) don't abort
+    /// `write_text` the moment they hit a non-alphanumeric character.
+    // The xkbcommon keysym constants keep their upstream X11 keysymdef
+    // names (mixed-case, e.g. `KEY_asciitilde`), which trips Rust's
+    // upper-case-constant lint when matched on directly.
+    #[allow(non_upper_case_globals)]
+    fn keysym_to_punctuation_key(keysym: xkbcommon::xkb::Keysym) -> Option<(Key, bool)> {
+        use xkbcommon::xkb::keysyms::*;
+
+        Some(match keysym.raw() {
+            KEY_grave => (Key::Grave, false),
+            KEY_asciitilde => (Key::Grave, true),
+            KEY_minus => (Key::Minus, false),
+            KEY_underscore => (Key::Minus, true),
+            KEY_equal => (Key::Equal, false),
+            KEY_plus => (Key::Equal, true),
+            KEY_bracketleft => (Key::LeftBrace, false),
+            KEY_braceleft => (Key::LeftBrace, true),
+            KEY_bracketright => (Key::RightBrace, false),
+            KEY_braceright => (Key::RightBrace, true),
+            KEY_backslash => (Key::BackSlash, false),
+            KEY_bar => (Key::BackSlash, true),
+            KEY_semicolon => (Key::SemiColon, false),
+            KEY_colon => (Key::SemiColon, true),
+            KEY_apostrophe => (Key::Apostrophe, false),
+            KEY_quotedbl => (Key::Apostrophe, true),
+            KEY_comma => (Key::Comma, false),
+            KEY_less => (Key::Comma, true),
+            KEY_period => (Key::Dot, false),
+            KEY_greater => (Key::Dot, true),
+            KEY_slash => (Key::Slash, false),
+            KEY_question => (Key::Slash, true),
+            KEY_exclam => (Key::_1, true),
+            KEY_at => (Key::_2, true),
+            KEY_numbersign => (Key::_3, true),
+            KEY_dollar => (Key::_4, true),
+            KEY_percent => (Key::_5, true),
+            KEY_asciicircum => (Key::_6, true),
+            KEY_ampersand => (Key::_7, true),
+            KEY_asterisk => (Key::_8, true),
+            KEY_parenleft => (Key::_9, true),
+            KEY_parenright => (Key::_0, true),
+            _ => return None,
+        })
+    }
+
+    fn ascii_letter_key(ch: char) -> Option<Key> {
+        Some(match ch {
+            'A' => Key::A,
+            'B' => Key::B,
+            'C' => Key::C,
+            'D' => Key::D,
+            'E' => Key::E,
+            'F' => Key::F,
+            'G' => Key::G,
+            'H' => Key::H,
+            'I' => Key::I,
+            'J' => Key::J,
+            'K' => Key::K,
+            'L' => Key::L,
+            'M' => Key::M,
+            'N' => Key::N,
+            'O' => Key::O,
+            'P' => Key::P,
+            'Q' => Key::Q,
+            'R' => Key::R,
+            'S' => Key::S,
+            'T' => Key::T,
+            'U' => Key::U,
+            'V' => Key::V,
+            'W' => Key::W,
+            'X' => Key::X,
+            'Y' => Key::Y,
+            'Z' => Key::Z,
+            _ => return None,
+        })
+    }
+
+    fn ascii_digit_key(ch: char) -> Option<Key> {
+        Some(match ch {
+            '0' => Key::_0,
+            '1' => Key::_1,
+            '2' => Key::_2,
+            '3' => Key::_3,
+            '4' => Key::_4,
+            '5' => Key::_5,
+            '6' => Key::_6,
+            '7' => Key::_7,
+            '8' => Key::_8,
+            '9' => Key::_9,
+            _ => return None,
+        })
+    }
+
+    fn modifier_to_key(modifier: super::ComboModifier) -> Key {
+        match modifier {
+            super::ComboModifier::Ctrl => Key::LeftControl,
+            super::ComboModifier::Shift => Key::LeftShift,
+            super::ComboModifier::Alt => Key::LeftAlt,
+            super::ComboModifier::AltGr => Key::RightAlt,
+            super::ComboModifier::Meta => Key::LeftMeta,
+        }
+    }
+
+    /// Resolve a combo's main key to a uinput key plus whether Shift must
+    /// be held, reusing the same character table `write_text` uses.
+    fn combo_key_to_key(key: super::ComboKey) -> Option<(Key, bool)> {
+        match key {
+            super::ComboKey::Char(ch) => keysym_to_key(ch),
+            super::ComboKey::Named(named) => named_key(named).map(|key| (key, false)),
+        }
+    }
+
+    fn named_key(named: super::NamedKey) -> Option<Key> {
+        use super::NamedKey::*;
+        Some(match named {
+            Enter => Key::Enter,
+            Escape => Key::Esc,
+            Tab => Key::Tab,
+            Space => Key::Space,
+            Backspace => Key::BackSpace,
+            Delete => Key::Delete,
+            Up => Key::Up,
+            Down => Key::Down,
+            Left => Key::Left,
+            Right => Key::Right,
+            Home => Key::Home,
+            End => Key::End,
+            PageUp => Key::PageUp,
+            PageDown => Key::PageDown,
+            F(1) => Key::F1,
+            F(2) => Key::F2,
+            F(3) => Key::F3,
+            F(4) => Key::F4,
+            F(5) => Key::F5,
+            F(6) => Key::F6,
+            F(7) => Key::F7,
+            F(8) => Key::F8,
+            F(9) => Key::F9,
+            F(10) => Key::F10,
+            F(11) => Key::F11,
+            F(12) => Key::F12,
+            F(_) => return None,
+        })
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn letters_and_digits_map_with_correct_shift() {
+            assert_eq!(keysym_to_key('a'), Some((Key::A, false)));
+            assert_eq!(keysym_to_key('A'), Some((Key::A, true)));
+            assert_eq!(keysym_to_key('5'), Some((Key::_5, false)));
+        }
+
+        #[test]
+        fn whitespace_keys_map_to_their_named_key() {
+            assert_eq!(keysym_to_key(' '), Some((Key::Space, false)));
+            assert_eq!(keysym_to_key('\n'), Some((Key::Enter, false)));
+            assert_eq!(keysym_to_key('\t'), Some((Key::Tab, false)));
+        }
+
+        #[test]
+        fn common_punctuation_maps_with_correct_shift() {
+            assert_eq!(keysym_to_key(','), Some((Key::Comma, false)));
+            assert_eq!(keysym_to_key('.'), Some((Key::Dot, false)));
+            assert_eq!(keysym_to_key('\''), Some((Key::Apostrophe, false)));
+            assert_eq!(keysym_to_key('"'), Some((Key::Apostrophe, true)));
+            assert_eq!(keysym_to_key('!'), Some((Key::_1, true)));
+            assert_eq!(keysym_to_key('?'), Some((Key::Slash, true)));
+            assert_eq!(keysym_to_key(':'), Some((Key::SemiColon, true)));
+        }
+
+        #[test]
+        fn shifted_number_row_symbols_map_to_the_digit_key() {
+            assert_eq!(keysym_to_key('@'), Some((Key::_2, true)));
+            assert_eq!(keysym_to_key('('), Some((Key::_9, true)));
+            assert_eq!(keysym_to_key(')'), Some((Key::_0, true)));
+        }
+
+        #[test]
+        fn unmapped_control_characters_return_none() {
+            assert_eq!(keysym_to_key('\u{0007}'), None);
+        }
+    }
+}
+
 
 /// Platform-specific initialization and capability checks
 mod platform {
     use super::PlatformError;
-    
+
+    /// Which input backend to drive text injection through on Linux.
+    /// Wayland compositors don't expose XTest, so a Wayland session is
+    /// routed to the uinput backend instead of Enigo.
+    #[cfg(target_os = "linux")]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum LinuxBackend {
+        X11,
+        Uinput,
+    }
+
+    #[cfg(target_os = "linux")]
+    impl std::fmt::Display for LinuxBackend {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self {
+                LinuxBackend::X11 => write!(f, "X11"),
+                LinuxBackend::Uinput => write!(f, "Wayland (uinput)"),
+            }
+        }
+    }
+
     /// Check platform requirements and initialize if necessary
     pub fn check_platform_requirements() -> Result<(), PlatformError> {
         #[cfg(target_os = "macos")]
         {
             check_macos_accessibility()
         }
-        
+
         #[cfg(target_os = "linux")]
         {
-            check_linux_display_server()
+            resolve_linux_backend().map(|_| ())
         }
-        
+
         #[cfg(target_os = "windows")]
         {
             // Windows doesn't require special checks for basic functionality
             Ok(())
         }
-        
+
         #[cfg(not(any(target_os = "windows", target_os = "macos", target_os = "linux")))]
         {
             Err(PlatformError::PlatformNotSupported)
         }
     }
-    
+
     #[cfg(target_os = "macos")]
     fn check_macos_accessibility() -> Result<(), PlatformError> {
-        // Note: We can't actually check accessibility permissions programmatically
-        // from Rust without additional dependencies, so we issue a warning
-        eprintln!("Warning: This application requires Accessibility permissions on macOS.");
-        eprintln!("If keyboard events are not working, please grant permissions in:");
-        eprintln!("System Preferences > Security & Privacy > Privacy > Accessibility");
-        Ok(())
+        if super::macos_accessibility::is_trusted(false) {
+            Ok(())
+        } else {
+            eprintln!("This application requires Accessibility permissions on macOS.");
+            eprintln!("Grant them in System Preferences > Security & Privacy > Privacy > Accessibility,");
+            eprintln!("or run `speakmcp-rs request-access` to trigger the system prompt.");
+            Err(PlatformError::AccessibilityDenied)
+        }
     }
-    
+
+    /// Decide which display server is in play and, by extension, which
+    /// input backend `write_text` should use. Wayland is now a supported
+    /// target (routed to uinput) rather than an immediate error; only the
+    /// complete absence of both `WAYLAND_DISPLAY` and `DISPLAY` fails.
     #[cfg(target_os = "linux")]
-    fn check_linux_display_server() -> Result<(), PlatformError> {
-        // Check if we're running under Wayland
+    pub fn check_linux_display_server() -> Result<LinuxBackend, PlatformError> {
         if std::env::var("WAYLAND_DISPLAY").is_ok() {
-            // Check if XDG_SESSION_TYPE explicitly says wayland
-            if let Ok(session_type) = std::env::var("XDG_SESSION_TYPE") {
-                if session_type.to_lowercase() == "wayland" {
-                    return Err(PlatformError::WaylandNotSupported);
+            return Ok(LinuxBackend::Uinput);
+        }
+
+        if std::env::var("DISPLAY").is_ok() {
+            return Ok(LinuxBackend::X11);
+        }
+
+        Err(PlatformError::X11NotAvailable)
+    }
+
+    /// Resolve which Linux input backend to use, honoring an explicit
+    /// `SPEAKMCP_BACKEND` override before falling back to the `auto`
+    /// heuristic in `check_linux_display_server`. This mirrors winit's
+    /// `WINIT_UNIX_BACKEND` convention: an explicit value is attempted
+    /// exclusively and fails loudly if unavailable, rather than silently
+    /// falling back to the other backend.
+    #[cfg(target_os = "linux")]
+    pub fn resolve_linux_backend() -> Result<LinuxBackend, PlatformError> {
+        match std::env::var("SPEAKMCP_BACKEND").ok().as_deref() {
+            Some("x11") => {
+                if std::env::var("DISPLAY").is_ok() {
+                    Ok(LinuxBackend::X11)
+                } else {
+                    Err(PlatformError::X11NotAvailable)
                 }
             }
-            
-            // If WAYLAND_DISPLAY is set but no X11 fallback, likely pure Wayland
-            if std::env::var("DISPLAY").is_err() {
-                return Err(PlatformError::WaylandNotSupported);
+            Some("wayland") => {
+                if std::env::var("WAYLAND_DISPLAY").is_ok() {
+                    Ok(LinuxBackend::Uinput)
+                } else {
+                    Err(PlatformError::InitializationFailed(
+                        "SPEAKMCP_BACKEND=wayland but WAYLAND_DISPLAY is not set".to_string(),
+                    ))
+                }
             }
+            // `uinput` forces the virtual-keyboard path outright; it needs
+            // neither display server, since it talks to the kernel input
+            // subsystem directly.
+            Some("uinput") => Ok(LinuxBackend::Uinput),
+            Some("auto") | None => check_linux_display_server(),
+            Some(other) => Err(PlatformError::InitializationFailed(format!(
+                "unknown SPEAKMCP_BACKEND value '{}': expected x11, wayland, uinput, or auto",
+                other
+            ))),
         }
-        
-        // Check if X11 is available
-        if std::env::var("DISPLAY").is_err() {
-            return Err(PlatformError::X11NotAvailable);
-        }
-        
-        Ok(())
     }
-    
+
     pub fn get_platform_info() -> String {
         #[cfg(target_os = "windows")]
         return "Windows".to_string();
@@ -109,13 +587,9 @@ mod platform {
         
         #[cfg(target_os = "linux")]
         {
-            let display_server = if std::env::var("WAYLAND_DISPLAY").is_ok() {
-                "Wayland"
-            } else if std::env::var("DISPLAY").is_ok() {
-                "X11"
-            } else {
-                "Unknown"
-            };
+            let display_server = resolve_linux_backend()
+                .map(|backend| backend.to_string())
+                .unwrap_or_else(|_| "Unknown".to_string());
             return format!("Linux ({})", display_server);
         }
         
@@ -124,6 +598,268 @@ mod platform {
     }
 }
 
+/// evdev-based keyboard listener for Wayland sessions, where rdev's
+/// XTest hook can't see global key presses. Reads raw `/dev/input/event*`
+/// devices and re-emits events through the same `deal_event_to_json`
+/// path the X11 listener uses, so the JSON shape on stdout - and the
+/// Electron side that consumes it - doesn't change.
+#[cfg(target_os = "linux")]
+mod evdev_backend {
+    use super::{deal_event_to_json, PlatformError};
+    use evdev::{Device, InputEventKind, Key as EvKey};
+    use rdev::{Event, EventType, Key as RKey};
+    use std::fs;
+    use std::io::ErrorKind;
+    use std::sync::mpsc;
+    use std::thread;
+    use std::time::SystemTime;
+
+    pub fn listen() -> Result<(), Box<dyn std::error::Error>> {
+        let devices = open_keyboard_devices()?;
+
+        eprintln!("Starting evdev keyboard listener on {} device(s)", devices.len());
+
+        let (tx, rx) = mpsc::channel();
+        for mut device in devices {
+            let tx = tx.clone();
+            thread::spawn(move || loop {
+                let Ok(events) = device.fetch_events() else {
+                    return;
+                };
+                for ev in events {
+                    if let InputEventKind::Key(key) = ev.kind() {
+                        // value: 0 = release, 1 = press, 2 = autorepeat (ignored)
+                        if (ev.value() == 0 || ev.value() == 1) && tx.send((key, ev.value() == 1)).is_err() {
+                            return;
+                        }
+                    }
+                }
+            });
+        }
+        drop(tx);
+
+        for (key, pressed) in rx {
+            let Some(key) = map_key(key) else {
+                continue;
+            };
+            let event = Event {
+                event_type: if pressed {
+                    EventType::KeyPress(key)
+                } else {
+                    EventType::KeyRelease(key)
+                },
+                time: SystemTime::now(),
+                name: None,
+            };
+            let json_event = deal_event_to_json(event);
+            println!("{}", serde_json::to_string(&json_event).unwrap());
+        }
+
+        Ok(())
+    }
+
+    /// Open every readable `/dev/input/event*` node that reports key
+    /// events. Reading evdev usually requires membership in the `input`
+    /// group or a udev rule granting access, so `EACCES` gets its own
+    /// actionable hint rather than a raw I/O error.
+    fn open_keyboard_devices() -> Result<Vec<Device>, Box<dyn std::error::Error>> {
+        let mut devices = Vec::new();
+        let mut saw_permission_denied = false;
+
+        for entry in fs::read_dir("/dev/input")? {
+            let path = entry?.path();
+            let is_event_node = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with("event"));
+            if !is_event_node {
+                continue;
+            }
+
+            match Device::open(&path) {
+                Ok(device) => {
+                    if is_keyboard(&device) {
+                        devices.push(device);
+                    }
+                }
+                Err(e) if e.kind() == ErrorKind::PermissionDenied => saw_permission_denied = true,
+                Err(_) => {}
+            }
+        }
+
+        if devices.is_empty() && saw_permission_denied {
+            eprintln!("Permission denied reading /dev/input/event*.");
+            eprintln!("Hint: add your user to the 'input' group and re-login, e.g.:");
+            eprintln!("  sudo usermod -aG input $USER");
+            return Err(Box::new(PlatformError::InitializationFailed(
+                "insufficient permissions to read evdev devices".to_string(),
+            )));
+        }
+
+        if devices.is_empty() {
+            return Err("no readable keyboard devices found under /dev/input".into());
+        }
+
+        Ok(devices)
+    }
+
+    /// `EventType::KEY` alone isn't enough to recognize a keyboard - mice
+    /// and touchpads report their buttons (`BTN_LEFT`, etc.) as `EV_KEY`
+    /// too. Require the letter/enter keys a real keyboard always has;
+    /// devices that also report relative-motion events are still accepted.
+    fn is_keyboard(device: &Device) -> bool {
+        // Gate on the keys a real keyboard always has rather than also
+        // excluding every device that reports EV_REL - composite
+        // keyboard+mouse nodes (gaming keyboards, laptop trackpoints) put
+        // both on the same device, and rejecting those would silently drop
+        // their keystrokes with no error.
+        device
+            .supported_keys()
+            .is_some_and(|keys| keys.contains(EvKey::KEY_A) && keys.contains(EvKey::KEY_ENTER))
+    }
+
+    /// Map a Linux evdev keycode to the same `rdev::Key` XTest listening
+    /// already reports, so downstream JSON is indistinguishable from the
+    /// X11 path. Covers letters, digits, modifiers, navigation keys and
+    /// F1-F12; anything else (punctuation keys, or a stray `BTN_*` code) is
+    /// dropped instead of forwarded as a fake key event.
+    fn map_key(code: EvKey) -> Option<RKey> {
+        match code {
+            EvKey::KEY_A => Some(RKey::KeyA),
+            EvKey::KEY_B => Some(RKey::KeyB),
+            EvKey::KEY_C => Some(RKey::KeyC),
+            EvKey::KEY_D => Some(RKey::KeyD),
+            EvKey::KEY_E => Some(RKey::KeyE),
+            EvKey::KEY_F => Some(RKey::KeyF),
+            EvKey::KEY_G => Some(RKey::KeyG),
+            EvKey::KEY_H => Some(RKey::KeyH),
+            EvKey::KEY_I => Some(RKey::KeyI),
+            EvKey::KEY_J => Some(RKey::KeyJ),
+            EvKey::KEY_K => Some(RKey::KeyK),
+            EvKey::KEY_L => Some(RKey::KeyL),
+            EvKey::KEY_M => Some(RKey::KeyM),
+            EvKey::KEY_N => Some(RKey::KeyN),
+            EvKey::KEY_O => Some(RKey::KeyO),
+            EvKey::KEY_P => Some(RKey::KeyP),
+            EvKey::KEY_Q => Some(RKey::KeyQ),
+            EvKey::KEY_R => Some(RKey::KeyR),
+            EvKey::KEY_S => Some(RKey::KeyS),
+            EvKey::KEY_T => Some(RKey::KeyT),
+            EvKey::KEY_U => Some(RKey::KeyU),
+            EvKey::KEY_V => Some(RKey::KeyV),
+            EvKey::KEY_W => Some(RKey::KeyW),
+            EvKey::KEY_X => Some(RKey::KeyX),
+            EvKey::KEY_Y => Some(RKey::KeyY),
+            EvKey::KEY_Z => Some(RKey::KeyZ),
+            EvKey::KEY_1 => Some(RKey::Num1),
+            EvKey::KEY_2 => Some(RKey::Num2),
+            EvKey::KEY_3 => Some(RKey::Num3),
+            EvKey::KEY_4 => Some(RKey::Num4),
+            EvKey::KEY_5 => Some(RKey::Num5),
+            EvKey::KEY_6 => Some(RKey::Num6),
+            EvKey::KEY_7 => Some(RKey::Num7),
+            EvKey::KEY_8 => Some(RKey::Num8),
+            EvKey::KEY_9 => Some(RKey::Num9),
+            EvKey::KEY_0 => Some(RKey::Num0),
+            EvKey::KEY_ENTER => Some(RKey::Return),
+            EvKey::KEY_ESC => Some(RKey::Escape),
+            EvKey::KEY_SPACE => Some(RKey::Space),
+            EvKey::KEY_BACKSPACE => Some(RKey::Backspace),
+            EvKey::KEY_TAB => Some(RKey::Tab),
+            EvKey::KEY_CAPSLOCK => Some(RKey::CapsLock),
+            EvKey::KEY_LEFTSHIFT => Some(RKey::ShiftLeft),
+            EvKey::KEY_RIGHTSHIFT => Some(RKey::ShiftRight),
+            EvKey::KEY_LEFTCTRL => Some(RKey::ControlLeft),
+            EvKey::KEY_RIGHTCTRL => Some(RKey::ControlRight),
+            EvKey::KEY_LEFTALT => Some(RKey::Alt),
+            EvKey::KEY_RIGHTALT => Some(RKey::AltGr),
+            EvKey::KEY_LEFTMETA => Some(RKey::MetaLeft),
+            EvKey::KEY_RIGHTMETA => Some(RKey::MetaRight),
+            EvKey::KEY_UP => Some(RKey::UpArrow),
+            EvKey::KEY_DOWN => Some(RKey::DownArrow),
+            EvKey::KEY_LEFT => Some(RKey::LeftArrow),
+            EvKey::KEY_RIGHT => Some(RKey::RightArrow),
+            EvKey::KEY_HOME => Some(RKey::Home),
+            EvKey::KEY_END => Some(RKey::End),
+            EvKey::KEY_PAGEUP => Some(RKey::PageUp),
+            EvKey::KEY_PAGEDOWN => Some(RKey::PageDown),
+            EvKey::KEY_INSERT => Some(RKey::Insert),
+            EvKey::KEY_DELETE => Some(RKey::Delete),
+            EvKey::KEY_F1 => Some(RKey::F1),
+            EvKey::KEY_F2 => Some(RKey::F2),
+            EvKey::KEY_F3 => Some(RKey::F3),
+            EvKey::KEY_F4 => Some(RKey::F4),
+            EvKey::KEY_F5 => Some(RKey::F5),
+            EvKey::KEY_F6 => Some(RKey::F6),
+            EvKey::KEY_F7 => Some(RKey::F7),
+            EvKey::KEY_F8 => Some(RKey::F8),
+            EvKey::KEY_F9 => Some(RKey::F9),
+            EvKey::KEY_F10 => Some(RKey::F10),
+            EvKey::KEY_F11 => Some(RKey::F11),
+            EvKey::KEY_F12 => Some(RKey::F12),
+            _ => None,
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn known_keys_map_to_the_matching_rdev_key() {
+            assert_eq!(map_key(EvKey::KEY_A), Some(RKey::KeyA));
+            assert_eq!(map_key(EvKey::KEY_ENTER), Some(RKey::Return));
+            assert_eq!(map_key(EvKey::KEY_LEFTSHIFT), Some(RKey::ShiftLeft));
+            assert_eq!(map_key(EvKey::KEY_F1), Some(RKey::F1));
+        }
+
+        #[test]
+        fn mouse_button_codes_are_dropped() {
+            assert_eq!(map_key(EvKey::BTN_LEFT), None);
+            assert_eq!(map_key(EvKey::BTN_RIGHT), None);
+        }
+
+        #[test]
+        fn unmapped_keyboard_codes_are_dropped() {
+            assert_eq!(map_key(EvKey::KEY_MICMUTE), None);
+        }
+    }
+}
+
+/// Programmatic macOS Accessibility trust checks via `AXIsProcessTrustedWithOptions`,
+/// so callers can tell "not granted" apart from "granted" instead of
+/// guessing from a blind warning.
+#[cfg(target_os = "macos")]
+mod macos_accessibility {
+    use core_foundation::base::TCFType;
+    use core_foundation::boolean::CFBoolean;
+    use core_foundation::dictionary::{CFDictionary, CFDictionaryRef};
+    use core_foundation::string::CFString;
+
+    #[link(name = "ApplicationServices", kind = "framework")]
+    extern "C" {
+        // Returns a C `Boolean` (`unsigned char`), not a Rust `bool` - any
+        // nonzero byte means true, and transmuting an arbitrary byte into
+        // `bool` is undefined behavior if the callee ever returns one.
+        fn AXIsProcessTrustedWithOptions(options: CFDictionaryRef) -> u8;
+    }
+
+    /// Check whether this process is trusted for Accessibility. When
+    /// `prompt` is true, passes `kAXTrustedCheckOptionPrompt` so the OS
+    /// shows the system permission dialog if trust hasn't been decided yet.
+    pub fn is_trusted(prompt: bool) -> bool {
+        if !prompt {
+            return unsafe { AXIsProcessTrustedWithOptions(std::ptr::null()) } != 0;
+        }
+
+        let key = CFString::new("AXTrustedCheckOptionPrompt");
+        let value = CFBoolean::true_value();
+        let options = CFDictionary::from_CFType_pairs(&[(key.as_CFType(), value.as_CFType())]);
+
+        unsafe { AXIsProcessTrustedWithOptions(options.as_concrete_TypeRef()) != 0 }
+    }
+}
+
 fn deal_event_to_json(event: Event) -> RdevEvent {
     let mut jsonify_event = RdevEvent {
         event_type: "".to_string(),
@@ -181,63 +917,397 @@ fn deal_event_to_json(event: Event) -> RdevEvent {
     jsonify_event
 }
 
-fn write_text(text: &str) -> Result<(), Box<dyn std::error::Error>> {
-    use enigo::{Enigo, Keyboard, Settings};
+/// A live text-injection handle. Kept alive across calls so the `serve`
+/// loop can reuse one Enigo/uinput instance instead of paying init cost
+/// (and losing any warmed-up backend state) on every command.
+enum InputBackend {
+    Enigo(enigo::Enigo),
+    #[cfg(target_os = "linux")]
+    Uinput(uinput_backend::UinputKeyboard),
+}
+
+impl InputBackend {
+    fn create() -> Result<Self, Box<dyn std::error::Error>> {
+        use enigo::{Enigo, Settings};
+
+        // Check platform requirements before attempting text input
+        if let Err(e) = platform::check_platform_requirements() {
+            let platform_info = platform::get_platform_info();
+            eprintln!("Platform error on {} - {}", platform_info, e);
+            return Err(Box::new(e));
+        }
 
-    // Check platform requirements before attempting text input
-    if let Err(e) = platform::check_platform_requirements() {
-        let platform_info = platform::get_platform_info();
-        eprintln!("Platform error on {} - {}", platform_info, e);
-        
         #[cfg(target_os = "linux")]
-        if matches!(e, PlatformError::WaylandNotSupported) {
-            eprintln!("Hint: Try running in an X11 session or install X11 compatibility layer");
+        if matches!(
+            platform::resolve_linux_backend(),
+            Ok(platform::LinuxBackend::Uinput)
+        ) {
+            return uinput_backend::UinputKeyboard::new()
+                .map(InputBackend::Uinput)
+                .map_err(|e| {
+                    eprintln!("Failed to create uinput keyboard: {}", e);
+                    Box::new(e) as Box<dyn std::error::Error>
+                });
+        }
+
+        match Enigo::new(&Settings::default()) {
+            Ok(enigo) => Ok(InputBackend::Enigo(enigo)),
+            Err(e) => {
+                let platform_info = platform::get_platform_info();
+                eprintln!("Failed to create Enigo instance on {}: {}", platform_info, e);
+
+                #[cfg(target_os = "macos")]
+                eprintln!("Hint: Ensure Accessibility permissions are granted in System Preferences");
+
+                #[cfg(target_os = "linux")]
+                eprintln!("Hint: Ensure you're running in an X11 session and have appropriate permissions");
+
+                Err(Box::new(e))
+            }
         }
-        
-        return Err(Box::new(e));
     }
 
-    let mut enigo = match Enigo::new(&Settings::default()) {
-        Ok(enigo) => enigo,
-        Err(e) => {
-            let platform_info = platform::get_platform_info();
-            eprintln!("Failed to create Enigo instance on {}: {}", platform_info, e);
-            
-            #[cfg(target_os = "macos")]
-            eprintln!("Hint: Ensure Accessibility permissions are granted in System Preferences");
-            
+    fn write_text(&mut self, text: &str) -> Result<(), Box<dyn std::error::Error>> {
+        match self {
+            InputBackend::Enigo(enigo) => {
+                use enigo::Keyboard;
+                enigo.text(text).map_err(|e| {
+                    let platform_info = platform::get_platform_info();
+                    eprintln!("Failed to write text on {}: {}", platform_info, e);
+                    Box::new(e) as Box<dyn std::error::Error>
+                })
+            }
             #[cfg(target_os = "linux")]
-            eprintln!("Hint: Ensure you're running in an X11 session and have appropriate permissions");
-            
-            return Err(Box::new(e));
+            InputBackend::Uinput(keyboard) => keyboard.write_text(text).map_err(|e| {
+                eprintln!("Failed to write text via uinput: {}", e);
+                Box::new(e) as Box<dyn std::error::Error>
+            }),
         }
-    };
+    }
 
-    match enigo.text(text) {
-        Ok(_) => Ok(()),
-        Err(e) => {
-            let platform_info = platform::get_platform_info();
-            eprintln!("Failed to write text on {}: {}", platform_info, e);
-            Err(Box::new(e))
+    /// Synthesize the platform paste shortcut: Cmd+V on macOS, Ctrl+V on
+    /// Linux/Windows.
+    fn paste(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        match self {
+            InputBackend::Enigo(enigo) => {
+                use enigo::{Direction, Key, Keyboard};
+
+                #[cfg(target_os = "macos")]
+                let modifier = Key::Meta;
+                #[cfg(not(target_os = "macos"))]
+                let modifier = Key::Control;
+
+                enigo.key(modifier, Direction::Press)?;
+                let result = enigo.key(Key::Unicode('v'), Direction::Click);
+                enigo.key(modifier, Direction::Release)?;
+                result.map_err(|e| Box::new(e) as Box<dyn std::error::Error>)
+            }
+            #[cfg(target_os = "linux")]
+            InputBackend::Uinput(keyboard) => keyboard.paste().map_err(|e| {
+                eprintln!("Failed to paste via uinput: {}", e);
+                Box::new(e) as Box<dyn std::error::Error>
+            }),
+        }
+    }
+
+    /// Hold `modifiers` while tapping `key`, releasing the modifiers in
+    /// reverse order - the general-purpose keystroke primitive behind the
+    /// `key` command (shortcuts like Enter, Ctrl+Z, Cmd+Tab).
+    fn send_key_combo(
+        &mut self,
+        modifiers: &[ComboModifier],
+        key: ComboKey,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        match self {
+            InputBackend::Enigo(enigo) => {
+                use enigo::{Direction, Keyboard};
+
+                let enigo_modifiers: Vec<enigo::Key> =
+                    modifiers.iter().copied().map(enigo_modifier_key).collect();
+                for m in &enigo_modifiers {
+                    enigo.key(*m, Direction::Press)?;
+                }
+
+                let result = match key {
+                    ComboKey::Char(ch) => enigo.key(enigo::Key::Unicode(ch), Direction::Click),
+                    ComboKey::Named(named) => match enigo_named_key(named) {
+                        Ok(key) => enigo.key(key, Direction::Click),
+                        Err(e) => {
+                            for m in enigo_modifiers.iter().rev() {
+                                let _ = enigo.key(*m, Direction::Release);
+                            }
+                            return Err(e);
+                        }
+                    },
+                };
+
+                for m in enigo_modifiers.iter().rev() {
+                    enigo.key(*m, Direction::Release)?;
+                }
+                result.map_err(|e| Box::new(e) as Box<dyn std::error::Error>)
+            }
+            #[cfg(target_os = "linux")]
+            InputBackend::Uinput(keyboard) => keyboard.send_combo(modifiers, key).map_err(|e| {
+                eprintln!("Failed to send key combo via uinput: {}", e);
+                Box::new(e) as Box<dyn std::error::Error>
+            }),
+        }
+    }
+}
+
+fn enigo_modifier_key(modifier: ComboModifier) -> enigo::Key {
+    use enigo::Key;
+    match modifier {
+        ComboModifier::Ctrl => Key::Control,
+        ComboModifier::Shift => Key::Shift,
+        // enigo doesn't expose a distinct AltGr modifier; Alt is the
+        // closest equivalent it can synthesize.
+        ComboModifier::Alt | ComboModifier::AltGr => Key::Alt,
+        ComboModifier::Meta => Key::Meta,
+    }
+}
+
+fn enigo_named_key(named: NamedKey) -> Result<enigo::Key, Box<dyn std::error::Error>> {
+    use enigo::Key;
+    Ok(match named {
+        NamedKey::Enter => Key::Return,
+        NamedKey::Escape => Key::Escape,
+        NamedKey::Tab => Key::Tab,
+        NamedKey::Space => Key::Space,
+        NamedKey::Backspace => Key::Backspace,
+        NamedKey::Delete => Key::Delete,
+        NamedKey::Up => Key::UpArrow,
+        NamedKey::Down => Key::DownArrow,
+        NamedKey::Left => Key::LeftArrow,
+        NamedKey::Right => Key::RightArrow,
+        NamedKey::Home => Key::Home,
+        NamedKey::End => Key::End,
+        NamedKey::PageUp => Key::PageUp,
+        NamedKey::PageDown => Key::PageDown,
+        NamedKey::F(1) => Key::F1,
+        NamedKey::F(2) => Key::F2,
+        NamedKey::F(3) => Key::F3,
+        NamedKey::F(4) => Key::F4,
+        NamedKey::F(5) => Key::F5,
+        NamedKey::F(6) => Key::F6,
+        NamedKey::F(7) => Key::F7,
+        NamedKey::F(8) => Key::F8,
+        NamedKey::F(9) => Key::F9,
+        NamedKey::F(10) => Key::F10,
+        NamedKey::F(11) => Key::F11,
+        NamedKey::F(12) => Key::F12,
+        NamedKey::F(n) => return Err(format!("unsupported function key F{}", n).into()),
+    })
+}
+
+/// Parse and send a key combo spec (e.g. `ctrl+shift+v`, `enter`).
+fn send_key_combo(combo: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let (modifiers, key) = parse_key_combo(combo)?;
+    InputBackend::create()?.send_key_combo(&modifiers, key)
+}
+
+fn write_text(text: &str) -> Result<(), Box<dyn std::error::Error>> {
+    InputBackend::create()?.write_text(text)
+}
+
+/// Clipboard access for the `paste` insertion mode, through `arboard`'s
+/// platform provider (macOS, Windows, X11). `arboard` is built with its
+/// default features, so on Linux it always uses the X11 clipboard - on a
+/// Wayland session that is the XWayland clipboard; its native Wayland
+/// provider (the `wayland-data-control` feature) is not enabled.
+mod clipboard {
+    use arboard::Clipboard;
+
+    /// Set the clipboard to `text`, returning its previous contents (if
+    /// any and if text) so the caller can restore them after the paste
+    /// shortcut fires.
+    pub fn set_text_preserving_previous(
+        text: &str,
+    ) -> Result<Option<String>, Box<dyn std::error::Error>> {
+        let mut clipboard = Clipboard::new()?;
+        let previous = clipboard.get_text().ok();
+        clipboard.set_text(text)?;
+        Ok(previous)
+    }
+
+    pub fn restore(previous: Option<String>) {
+        let Some(text) = previous else { return };
+        match Clipboard::new().and_then(|mut clipboard| clipboard.set_text(text)) {
+            Ok(_) => {}
+            Err(e) => eprintln!("Warning: failed to restore previous clipboard contents: {}", e),
         }
     }
 }
 
+/// Set the clipboard to `text` and synthesize the platform paste shortcut
+/// through `backend`, restoring the user's prior clipboard contents
+/// afterwards. Faster and more reliable for long or non-ASCII text than
+/// typing character by character via `write_text`.
+fn paste_via(backend: &mut InputBackend, text: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let previous = clipboard::set_text_preserving_previous(text)?;
+
+    let result = backend.paste();
+
+    // Give the focused application a moment to read the clipboard before
+    // we put the old contents back.
+    std::thread::sleep(std::time::Duration::from_millis(100));
+    clipboard::restore(previous);
+
+    result
+}
+
+fn paste_text(text: &str) -> Result<(), Box<dyn std::error::Error>> {
+    paste_via(&mut InputBackend::create()?, text)
+}
+
+/// One line of the `serve` command protocol: newline-delimited JSON read
+/// from stdin, tagged by `cmd`.
+#[derive(Deserialize)]
+#[serde(tag = "cmd", rename_all = "lowercase")]
+enum ServeCommand {
+    Write { text: String },
+    Paste { text: String },
+    Key { combo: String },
+    Info,
+}
+
+#[derive(Serialize)]
+struct ServeResponse {
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    platform: Option<String>,
+}
+
+impl ServeResponse {
+    fn ok() -> Self {
+        ServeResponse { ok: true, error: None, platform: None }
+    }
+
+    fn err(message: impl std::fmt::Display) -> Self {
+        ServeResponse { ok: false, error: Some(message.to_string()), platform: None }
+    }
+
+    fn info() -> Self {
+        ServeResponse {
+            ok: true,
+            error: None,
+            platform: Some(platform::get_platform_info()),
+        }
+    }
+}
+
+/// Keep the process alive and serve newline-delimited JSON commands from
+/// stdin, reusing one `InputBackend` for the process lifetime instead of
+/// re-spawning (and re-initializing Enigo/uinput) per invocation. Useful
+/// when the Electron app dictates many short segments in quick succession.
+fn run_serve_loop() -> Result<(), Box<dyn std::error::Error>> {
+    let platform_info = platform::get_platform_info();
+    eprintln!("Serving commands on stdin ({})", platform_info);
+
+    let mut backend: Option<InputBackend> = None;
+    let stdin = io::stdin();
+    let mut stdout = io::stdout();
+
+    for line in stdin.lock().lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let response = match serde_json::from_str::<ServeCommand>(&line) {
+            Ok(ServeCommand::Info) => ServeResponse::info(),
+            Ok(ServeCommand::Write { text }) => {
+                let backend = match ensure_backend(&mut backend, &mut stdout)? {
+                    Some(backend) => backend,
+                    None => continue,
+                };
+                match backend.write_text(&text) {
+                    Ok(_) => ServeResponse::ok(),
+                    Err(e) => ServeResponse::err(e),
+                }
+            }
+            Ok(ServeCommand::Paste { text }) => {
+                let backend = match ensure_backend(&mut backend, &mut stdout)? {
+                    Some(backend) => backend,
+                    None => continue,
+                };
+                match paste_via(backend, &text) {
+                    Ok(_) => ServeResponse::ok(),
+                    Err(e) => ServeResponse::err(e),
+                }
+            }
+            Ok(ServeCommand::Key { combo }) => {
+                let backend = match ensure_backend(&mut backend, &mut stdout)? {
+                    Some(backend) => backend,
+                    None => continue,
+                };
+                match parse_key_combo(&combo).map_err(|e| e.into()).and_then(
+                    |(modifiers, key)| backend.send_key_combo(&modifiers, key),
+                ) {
+                    Ok(_) => ServeResponse::ok(),
+                    Err(e) => ServeResponse::err(e),
+                }
+            }
+            Err(e) => ServeResponse::err(format!("invalid command: {}", e)),
+        };
+
+        write_response(&mut stdout, &response)?;
+    }
+
+    Ok(())
+}
+
+fn write_response(
+    stdout: &mut impl Write,
+    response: &ServeResponse,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let line = serde_json::to_string(response)?;
+    writeln!(stdout, "{}", line)?;
+    stdout.flush()?;
+    Ok(())
+}
+
+/// Lazily create the shared `InputBackend` on first use. Returns `Ok(None)`
+/// (after writing an error response) when creation fails, so callers can
+/// `continue` the serve loop without duplicating the error path.
+fn ensure_backend<'a>(
+    backend: &'a mut Option<InputBackend>,
+    stdout: &mut impl Write,
+) -> Result<Option<&'a mut InputBackend>, Box<dyn std::error::Error>> {
+    if backend.is_none() {
+        match InputBackend::create() {
+            Ok(created) => {
+                *backend = Some(created);
+            }
+            Err(e) => {
+                write_response(stdout, &ServeResponse::err(e))?;
+                return Ok(None);
+            }
+        }
+    }
+    Ok(backend.as_mut())
+}
+
 fn listen_for_events() -> Result<(), Box<dyn std::error::Error>> {
     // Check platform requirements before starting listener
     if let Err(e) = platform::check_platform_requirements() {
         let platform_info = platform::get_platform_info();
         eprintln!("Cannot start event listener on {} - {}", platform_info, e);
-        
-        #[cfg(target_os = "linux")]
-        if matches!(e, PlatformError::WaylandNotSupported) {
-            eprintln!("Hint: Switch to an X11 session to use keyboard monitoring");
-            eprintln!("You can usually switch at the login screen or install X11 compatibility");
-        }
-        
         return Err(Box::new(e));
     }
 
+    // rdev's hook depends on XTest, which Wayland compositors don't expose
+    // over the X protocol, so route Wayland sessions to the evdev listener.
+    #[cfg(target_os = "linux")]
+    if matches!(
+        platform::resolve_linux_backend(),
+        Ok(platform::LinuxBackend::Uinput)
+    ) {
+        return evdev_backend::listen();
+    }
+
     let platform_info = platform::get_platform_info();
     eprintln!("Starting keyboard event listener on {}", platform_info);
 
@@ -258,20 +1328,31 @@ fn listen_for_events() -> Result<(), Box<dyn std::error::Error>> {
 fn show_usage(program_name: &str) {
     let platform_info = platform::get_platform_info();
     eprintln!("SpeakMCP Rust Platform Helper ({})", platform_info);
-    eprintln!("Usage: {} [listen|write <text>|info]", program_name);
+    eprintln!(
+        "Usage: {} [listen|write <text>|paste <text>|key <combo>|info|serve|request-access]",
+        program_name
+    );
     eprintln!("Commands:");
-    eprintln!("  listen       - Listen for keyboard events");
-    eprintln!("  write <text> - Write text using accessibility API");
-    eprintln!("  info         - Show platform information");
+    eprintln!("  listen          - Listen for keyboard events");
+    eprintln!("  write <text>    - Write text using accessibility API");
+    eprintln!("  paste <text>    - Set clipboard and synthesize a paste shortcut");
+    eprintln!("  key <combo>     - Simulate a key combo, e.g. ctrl+shift+v, enter, f5");
+    eprintln!("  info            - Show platform information");
+    eprintln!("  serve           - Read newline-delimited JSON commands from stdin");
+    eprintln!("  request-access  - Trigger the macOS Accessibility permission prompt");
     eprintln!();
     eprintln!("Platform-specific notes:");
-    
+
     #[cfg(target_os = "macos")]
-    eprintln!("  - Requires Accessibility permissions in System Preferences");
+    eprintln!("  - Requires Accessibility permissions; run `request-access` if not yet granted");
     
     #[cfg(target_os = "linux")]
-    eprintln!("  - Requires X11 session (Wayland not supported)");
-    
+    {
+        eprintln!("  - `write`/`listen` support X11 and Wayland (via uinput/evdev)");
+        eprintln!("  - Reading evdev devices for `listen` on Wayland usually needs `input` group membership");
+        eprintln!("  - Set SPEAKMCP_BACKEND=x11|wayland|uinput to force a backend (default: auto)");
+    }
+
     #[cfg(target_os = "windows")]
     eprintln!("  - No special requirements");
 }
@@ -279,7 +1360,12 @@ fn show_usage(program_name: &str) {
 fn show_platform_info() {
     let platform_info = platform::get_platform_info();
     println!("Platform: {}", platform_info);
-    
+
+    #[cfg(target_os = "linux")]
+    if let Ok(backend) = env::var("SPEAKMCP_BACKEND") {
+        println!("SPEAKMCP_BACKEND override: {}", backend);
+    }
+
     match platform::check_platform_requirements() {
         Ok(_) => println!("Status: Ready"),
         Err(e) => {
@@ -291,8 +1377,8 @@ fn show_platform_info() {
             }
             
             #[cfg(target_os = "linux")]
-            if matches!(e, PlatformError::WaylandNotSupported) {
-                println!("Resolution: Switch to an X11 session or log out and select X11 at login screen");
+            if matches!(e, PlatformError::X11NotAvailable) {
+                println!("Resolution: No X11 or Wayland display found - check DISPLAY/WAYLAND_DISPLAY are set");
             }
         }
     }
@@ -343,13 +1429,131 @@ fn main() {
                 }
             }
         }
+        Some("paste") => {
+            if args.len() < 3 {
+                eprintln!("Error: paste command requires text argument");
+                show_usage(program_name);
+                process::exit(1);
+            }
+
+            let text = &args[2];
+            match paste_text(text) {
+                Ok(_) => {
+                    process::exit(0);
+                }
+                Err(e) => {
+                    eprintln!("Paste command failed: {}", e);
+                    process::exit(101);
+                }
+            }
+        }
+        Some("key") => {
+            if args.len() < 3 {
+                eprintln!("Error: key command requires a combo argument");
+                show_usage(program_name);
+                process::exit(1);
+            }
+
+            let combo = &args[2];
+            match send_key_combo(combo) {
+                Ok(_) => {
+                    process::exit(0);
+                }
+                Err(e) => {
+                    eprintln!("Key command failed: {}", e);
+                    process::exit(101);
+                }
+            }
+        }
         Some("info") => {
             show_platform_info();
             process::exit(0);
         }
+        Some("request-access") => {
+            #[cfg(target_os = "macos")]
+            {
+                let trusted = macos_accessibility::is_trusted(true);
+                println!("Accessibility trusted: {}", trusted);
+                process::exit(if trusted { 0 } else { 1 });
+            }
+
+            #[cfg(not(target_os = "macos"))]
+            {
+                println!("request-access is only meaningful on macOS; nothing to do here");
+                process::exit(0);
+            }
+        }
+        Some("serve") => {
+            if let Err(e) = run_serve_loop() {
+                eprintln!("Serve command failed: {}", e);
+                process::exit(1);
+            }
+        }
         _ => {
             show_usage(program_name);
             process::exit(1);
         }
     }
 }
+
+#[cfg(test)]
+mod combo_parsing_tests {
+    use super::*;
+
+    #[test]
+    fn single_char_is_a_char_combo() {
+        assert_eq!(parse_combo_key("a").unwrap(), ComboKey::Char('a'));
+    }
+
+    #[test]
+    fn named_keys_are_case_insensitive() {
+        assert_eq!(parse_combo_key("Enter").unwrap(), ComboKey::Named(NamedKey::Enter));
+        assert_eq!(parse_combo_key("ESC").unwrap(), ComboKey::Named(NamedKey::Escape));
+        assert_eq!(parse_combo_key("PageDown").unwrap(), ComboKey::Named(NamedKey::PageDown));
+    }
+
+    #[test]
+    fn function_keys_parse_numeric_suffix() {
+        assert_eq!(parse_combo_key("f5").unwrap(), ComboKey::Named(NamedKey::F(5)));
+        assert_eq!(parse_combo_key("F12").unwrap(), ComboKey::Named(NamedKey::F(12)));
+    }
+
+    #[test]
+    fn unknown_key_name_is_an_error() {
+        assert!(parse_combo_key("nonsense").is_err());
+    }
+
+    #[test]
+    fn combo_splits_modifiers_from_main_key() {
+        let (modifiers, key) = parse_key_combo("ctrl+shift+v").unwrap();
+        assert_eq!(modifiers, vec![ComboModifier::Ctrl, ComboModifier::Shift]);
+        assert_eq!(key, ComboKey::Char('v'));
+    }
+
+    #[test]
+    fn combo_accepts_modifier_aliases() {
+        let (modifiers, _) = parse_key_combo("cmd+option+altgr+a").unwrap();
+        assert_eq!(
+            modifiers,
+            vec![ComboModifier::Meta, ComboModifier::Alt, ComboModifier::AltGr]
+        );
+    }
+
+    #[test]
+    fn combo_with_no_modifiers() {
+        let (modifiers, key) = parse_key_combo("enter").unwrap();
+        assert!(modifiers.is_empty());
+        assert_eq!(key, ComboKey::Named(NamedKey::Enter));
+    }
+
+    #[test]
+    fn empty_combo_is_an_error() {
+        assert!(parse_key_combo("").is_err());
+        assert!(parse_key_combo("+").is_err());
+    }
+
+    #[test]
+    fn unknown_modifier_is_an_error() {
+        assert!(parse_key_combo("hyper+a").is_err());
+    }
+}